@@ -37,6 +37,67 @@ fn fibonacci(n: u32) -> u64 {
     }
 }
 
+/// Calculates the nth Fibonacci number, returning `None` on overflow instead of wrapping silently.
+///
+/// # Arguments
+///
+/// * `n` - The position in the Fibonacci sequence (0-based) for which to calculate the Fibonacci number.
+///
+/// # Returns
+///
+/// `Some` containing the nth Fibonacci number as a `u64`, or `None` if it would overflow `u64`.
+fn fibonacci_checked(n: u32) -> Option<u64> {
+    match n {
+        0 => Some(0),
+        1 => Some(1),
+        _ => {
+            let mut a: u64 = 0;
+            let mut b: u64 = 1;
+            for _ in 0..n - 1 {
+                let next = a.checked_add(b)?;
+                a = b;
+                b = next;
+            }
+            Some(b)
+        }
+    }
+}
+
+/// Calculates the nth Fibonacci number in `O(log n)` time using the fast-doubling identities.
+///
+/// Uses wrapping arithmetic throughout, so for `n >= 94` (where `F(n)` no
+/// longer fits in a `u64`) this silently returns a wrapped, incorrect value
+/// rather than panicking or signaling overflow. Use [`fibonacci_checked`]
+/// when `n` isn't known to be small enough to fit.
+///
+/// # Arguments
+///
+/// * `n` - The position in the Fibonacci sequence (0-based) for which to calculate the Fibonacci number.
+///
+/// # Returns
+///
+/// The nth Fibonacci number as a `u64`, wrapped on overflow.
+fn fibonacci_fast(n: u32) -> u64 {
+    fibonacci_fast_pair(n).0
+}
+
+/// Computes `(F(n), F(n+1))` by doubling over the bits of `n` from most significant to least.
+fn fibonacci_fast_pair(n: u32) -> (u64, u64) {
+    if n == 0 {
+        return (0, 1);
+    }
+
+    let (a, b) = fibonacci_fast_pair(n / 2);
+    let c = a.wrapping_mul(b.wrapping_mul(2).wrapping_sub(a));
+    let d = a.wrapping_mul(a).wrapping_add(b.wrapping_mul(b));
+
+    if n.is_multiple_of(2) {
+        (c, d)
+    } else {
+        (d, c.wrapping_add(d))
+    }
+}
+
 /// Splits a slice into a vector of vectors, each containing a specified number of elements.
 /// 
 /// # Arguments
@@ -53,6 +114,82 @@ fn chunk_vec<T: Clone>(vec: &[T], chunk_size: usize) -> Vec<Vec<T>> {
         .collect()
 }
 
+/// Splits a slice into a vector of vectors counted from the end, so that any
+/// short chunk holds the leading elements of the slice instead of the
+/// trailing ones. Chunks are returned in back-to-front order, so that short
+/// chunk is the last entry of the returned vector.
+///
+/// # Arguments
+///
+/// * `vec` - A slice of elements to be split into chunks.
+/// * `chunk_size` - The maximum number of elements each chunk can contain.
+///
+/// # Returns
+///
+/// A vector of vectors, where each inner vector contains up to `chunk_size`
+/// elements from the original slice, chunked from the back.
+fn rchunk_vec<T: Clone>(vec: &[T], chunk_size: usize) -> Vec<Vec<T>> {
+    vec.rchunks(chunk_size)
+        .map(|chunk| chunk.to_vec())
+        .collect()
+}
+
+/// Splits a slice into full-size chunks only, returning the leftover
+/// remainder separately instead of emitting a short final chunk.
+///
+/// # Arguments
+///
+/// * `vec` - A slice of elements to be split into chunks.
+/// * `chunk_size` - The exact number of elements each chunk must contain.
+///
+/// # Returns
+///
+/// `None` if `chunk_size` is `0`. Otherwise `Some((chunks, remainder))` where
+/// `chunks` contains only full-size chunks and `remainder` holds the
+/// trailing elements (length `< chunk_size`) that didn't fill a whole chunk.
+fn chunk_vec_exact<T: Clone>(vec: &[T], chunk_size: usize) -> Option<(Vec<Vec<T>>, Vec<T>)> {
+    if chunk_size == 0 {
+        return None;
+    }
+
+    let split = (vec.len() / chunk_size) * chunk_size;
+    let chunks = vec[..split]
+        .chunks_exact(chunk_size)
+        .map(|chunk| chunk.to_vec())
+        .collect();
+    let remainder = vec[split..].to_vec();
+    Some((chunks, remainder))
+}
+
+/// Splits a slice into full-size chunks counted from the end, returning the
+/// leftover remainder (from the front) separately instead of emitting a
+/// short chunk.
+///
+/// # Arguments
+///
+/// * `vec` - A slice of elements to be split into chunks.
+/// * `chunk_size` - The exact number of elements each chunk must contain.
+///
+/// # Returns
+///
+/// `None` if `chunk_size` is `0`. Otherwise `Some((chunks, remainder))` where
+/// `chunks` contains only full-size chunks taken from the back and
+/// `remainder` holds the leading elements (length `< chunk_size`) that
+/// didn't fill a whole chunk.
+fn rchunk_vec_exact<T: Clone>(vec: &[T], chunk_size: usize) -> Option<(Vec<Vec<T>>, Vec<T>)> {
+    if chunk_size == 0 {
+        return None;
+    }
+
+    let split = vec.len() - (vec.len() / chunk_size) * chunk_size;
+    let chunks = vec[split..]
+        .rchunks_exact(chunk_size)
+        .map(|chunk| chunk.to_vec())
+        .collect();
+    let remainder = vec[..split].to_vec();
+    Some((chunks, remainder))
+}
+
 /// Removes duplicate elements from a slice and returns a new vector containing only unique elements.
 /// 
 /// # Arguments
@@ -123,4 +260,419 @@ fn merge_sorted_vecs<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
     result.extend_from_slice(&a[i..]);
     result.extend_from_slice(&b[j..]);
     result
+}
+
+/// Sorts a slice in place using a 3-way (Dutch national flag) quicksort,
+/// which stays near-linear on inputs with many duplicate keys.
+///
+/// # Arguments
+///
+/// * `arr` - A mutable slice of elements of type `T`, which must implement the `Ord` trait.
+fn quicksort<T: Ord>(arr: &mut [T]) {
+    quicksort_by(arr, |a, b| a.cmp(b))
+}
+
+/// Sorts a slice in place using a 3-way (Dutch national flag) quicksort with
+/// a custom comparator, which stays near-linear on inputs with many
+/// duplicate keys.
+///
+/// # Arguments
+///
+/// * `arr` - A mutable slice of elements of type `T`.
+/// * `compare` - A comparator used to order the elements.
+fn quicksort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    quicksort_range(arr, 0, arr.len() as isize - 1, &mut compare);
+}
+
+fn quicksort_range<T, F>(arr: &mut [T], mut lo: isize, mut hi: isize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    // Recurse into the smaller partition and loop on the larger one so the
+    // recursion depth stays O(log n) even though median-of-three pivoting
+    // only makes the quadratic worst case unlikely, not impossible.
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        median_of_three_to_lo(arr, lo, mid, hi, compare);
+
+        let mut lt = lo;
+        let mut gt = hi + 1;
+        let mut i = lo + 1;
+
+        while i < gt {
+            match compare(&arr[i as usize], &arr[lo as usize]) {
+                std::cmp::Ordering::Less => {
+                    arr.swap((lt + 1) as usize, i as usize);
+                    lt += 1;
+                    i += 1;
+                }
+                std::cmp::Ordering::Greater => {
+                    arr.swap(i as usize, (gt - 1) as usize);
+                    gt -= 1;
+                }
+                std::cmp::Ordering::Equal => {
+                    i += 1;
+                }
+            }
+        }
+        arr.swap(lo as usize, lt as usize);
+
+        if lt - lo < hi - gt {
+            quicksort_range(arr, lo, lt - 1, compare);
+            lo = gt;
+        } else {
+            quicksort_range(arr, gt, hi, compare);
+            hi = lt - 1;
+        }
+    }
+}
+
+/// Sorts `arr[lo]`, `arr[mid]`, and `arr[hi]` into ascending order and leaves
+/// the resulting median at `arr[lo]`, so a 3-way partition pivoting on `lo`
+/// no longer degenerates to O(n^2) on already-sorted or reverse-sorted input.
+fn median_of_three_to_lo<T, F>(arr: &mut [T], lo: isize, mid: isize, hi: isize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    if compare(&arr[mid as usize], &arr[lo as usize]) == std::cmp::Ordering::Less {
+        arr.swap(lo as usize, mid as usize);
+    }
+    if compare(&arr[hi as usize], &arr[lo as usize]) == std::cmp::Ordering::Less {
+        arr.swap(lo as usize, hi as usize);
+    }
+    if compare(&arr[hi as usize], &arr[mid as usize]) == std::cmp::Ordering::Less {
+        arr.swap(mid as usize, hi as usize);
+    }
+    arr.swap(lo as usize, mid as usize);
+}
+
+/// Computes the union of two sorted slices in a single linear merge pass.
+///
+/// # Arguments
+///
+/// * `a` - A sorted slice of elements of type `T`, which must implement the `Ord` and `Clone` traits.
+/// * `b` - A sorted slice of elements of type `T`, which must implement the `Ord` and `Clone` traits.
+///
+/// # Returns
+///
+/// A sorted vector containing every distinct element present in either `a` or `b`, with duplicates
+/// (both within and across the two slices) collapsed.
+fn union<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut result = Vec::with_capacity(a.len() + b.len());
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => {
+                result.push(a[i].clone());
+                i = skip_run(a, i);
+            }
+            std::cmp::Ordering::Greater => {
+                result.push(b[j].clone());
+                j = skip_run(b, j);
+            }
+            std::cmp::Ordering::Equal => {
+                result.push(a[i].clone());
+                i = skip_run(a, i);
+                j = skip_run(b, j);
+            }
+        }
+    }
+
+    while i < a.len() {
+        result.push(a[i].clone());
+        i = skip_run(a, i);
+    }
+    while j < b.len() {
+        result.push(b[j].clone());
+        j = skip_run(b, j);
+    }
+    result
+}
+
+/// Computes the intersection of two sorted slices in a single linear merge pass.
+///
+/// # Arguments
+///
+/// * `a` - A sorted slice of elements of type `T`, which must implement the `Ord` and `Clone` traits.
+/// * `b` - A sorted slice of elements of type `T`, which must implement the `Ord` and `Clone` traits.
+///
+/// # Returns
+///
+/// A sorted vector containing only the distinct elements present in both `a` and `b`.
+fn intersection<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut result = Vec::new();
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i = skip_run(a, i),
+            std::cmp::Ordering::Greater => j = skip_run(b, j),
+            std::cmp::Ordering::Equal => {
+                result.push(a[i].clone());
+                i = skip_run(a, i);
+                j = skip_run(b, j);
+            }
+        }
+    }
+
+    result
+}
+
+/// Computes the difference `a - b` of two sorted slices in a single linear merge pass.
+///
+/// # Arguments
+///
+/// * `a` - A sorted slice of elements of type `T`, which must implement the `Ord` and `Clone` traits.
+/// * `b` - A sorted slice of elements of type `T`, which must implement the `Ord` and `Clone` traits.
+///
+/// # Returns
+///
+/// A sorted vector containing the distinct elements of `a` that are not present in `b`.
+fn difference<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut result = Vec::new();
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => {
+                result.push(a[i].clone());
+                i = skip_run(a, i);
+            }
+            std::cmp::Ordering::Greater => j = skip_run(b, j),
+            std::cmp::Ordering::Equal => {
+                i = skip_run(a, i);
+                j = skip_run(b, j);
+            }
+        }
+    }
+
+    while i < a.len() {
+        result.push(a[i].clone());
+        i = skip_run(a, i);
+    }
+    result
+}
+
+/// Computes the symmetric difference of two sorted slices in a single linear merge pass.
+///
+/// # Arguments
+///
+/// * `a` - A sorted slice of elements of type `T`, which must implement the `Ord` and `Clone` traits.
+/// * `b` - A sorted slice of elements of type `T`, which must implement the `Ord` and `Clone` traits.
+///
+/// # Returns
+///
+/// A sorted vector containing the distinct elements present in exactly one of `a` or `b`.
+fn symmetric_difference<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut result = Vec::new();
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => {
+                result.push(a[i].clone());
+                i = skip_run(a, i);
+            }
+            std::cmp::Ordering::Greater => {
+                result.push(b[j].clone());
+                j = skip_run(b, j);
+            }
+            std::cmp::Ordering::Equal => {
+                i = skip_run(a, i);
+                j = skip_run(b, j);
+            }
+        }
+    }
+
+    while i < a.len() {
+        result.push(a[i].clone());
+        i = skip_run(a, i);
+    }
+    while j < b.len() {
+        result.push(b[j].clone());
+        j = skip_run(b, j);
+    }
+    result
+}
+
+/// Advances past the run of elements at `arr[i]` equal to `arr[i]`, returning the index of the
+/// next distinct element (or `arr.len()`). Used by the sorted-slice set operations so repeated
+/// values within a single input don't pass through more than once.
+fn skip_run<T: Eq>(arr: &[T], i: usize) -> usize {
+    let mut i = i + 1;
+    while i < arr.len() && arr[i] == arr[i - 1] {
+        i += 1;
+    }
+    i
+}
+
+/// Tests whether `needle` occurs contiguously within `haystack`.
+///
+/// # Arguments
+///
+/// * `haystack` - The slice of elements of type `T` to search within.
+/// * `needle` - The subslice of elements of type `T` to search for.
+///
+/// # Returns
+///
+/// `true` if `needle` occurs as a contiguous run somewhere in `haystack`, `false` otherwise.
+fn contains_subslice<T: PartialEq>(haystack: &[T], needle: &[T]) -> bool {
+    if needle.is_empty() {
+        return true;
+    }
+    if needle.len() > haystack.len() {
+        return false;
+    }
+
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+/// Returns the indices that would sort `arr`, using a stable sort so equal
+/// elements keep their relative order.
+///
+/// # Arguments
+///
+/// * `arr` - A slice of elements of type `T`, which must implement the `Ord` trait.
+///
+/// # Returns
+///
+/// A vector of indices `idx` such that `arr[idx[0]], arr[idx[1]], ...` is sorted ascending.
+fn argsort<T: Ord>(arr: &[T]) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..arr.len()).collect();
+    indices.sort_by(|&i, &j| arr[i].cmp(&arr[j]));
+    indices
+}
+
+/// Returns the rank of each element of `arr`, the inverse permutation of `argsort`.
+///
+/// # Arguments
+///
+/// * `arr` - A slice of elements of type `T`, which must implement the `Ord` trait.
+///
+/// # Returns
+///
+/// A vector where `rank[i]` is the position element `arr[i]` would occupy in sorted order.
+fn rank<T: Ord>(arr: &[T]) -> Vec<usize> {
+    let sorted_indices = argsort(arr);
+    let mut rank = vec![0; arr.len()];
+    for (pos, &sorted_index) in sorted_indices.iter().enumerate() {
+        rank[sorted_index] = pos;
+    }
+    rank
+}
+
+/// Finds the first index in a sorted slice where the element is not less than `target`.
+///
+/// # Arguments
+///
+/// * `arr` - A sorted slice of elements of type `T` to search through.
+/// * `target` - A reference to the target value of type `T`.
+///
+/// # Returns
+///
+/// The first index `i` such that `arr[i] >= target`, or `arr.len()` if no such index exists.
+fn lower_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+    let mut left = 0;
+    let mut right = arr.len();
+
+    while left < right {
+        let mid = left + (right - left) / 2;
+        if &arr[mid] < target {
+            left = mid + 1;
+        } else {
+            right = mid;
+        }
+    }
+    left
+}
+
+/// Finds the first index in a sorted slice where the element is greater than `target`.
+///
+/// # Arguments
+///
+/// * `arr` - A sorted slice of elements of type `T` to search through.
+/// * `target` - A reference to the target value of type `T`.
+///
+/// # Returns
+///
+/// The first index `i` such that `arr[i] > target`, or `arr.len()` if no such index exists.
+fn upper_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+    let mut left = 0;
+    let mut right = arr.len();
+
+    while left < right {
+        let mid = left + (right - left) / 2;
+        if &arr[mid] <= target {
+            left = mid + 1;
+        } else {
+            right = mid;
+        }
+    }
+    left
+}
+
+/// Finds the half-open range of indices in a sorted slice whose elements equal `target`.
+///
+/// # Arguments
+///
+/// * `arr` - A sorted slice of elements of type `T` to search through.
+/// * `target` - A reference to the target value of type `T`.
+///
+/// # Returns
+///
+/// A `(start, end)` pair such that `arr[start..end]` contains exactly the elements equal to `target`.
+fn equal_range<T: Ord>(arr: &[T], target: &T) -> (usize, usize) {
+    (lower_bound(arr, target), upper_bound(arr, target))
+}
+
+/// Counts the positions where two equal-length slices differ.
+///
+/// # Arguments
+///
+/// * `a` - A slice of elements of type `T`, which must implement the `Eq` trait.
+/// * `b` - A slice of elements of type `T`, which must implement the `Eq` trait.
+///
+/// # Returns
+///
+/// `Some` containing the number of positions where `a` and `b` differ, or `None` if their lengths don't match.
+fn hamming_distance<T: Eq>(a: &[T], b: &[T]) -> Option<usize> {
+    if a.len() != b.len() {
+        return None;
+    }
+
+    Some(a.iter().zip(b.iter()).filter(|(x, y)| x != y).count())
+}
+
+/// Sums a per-position cost between two equal-length slices.
+///
+/// # Arguments
+///
+/// * `a` - A slice of elements of type `T`.
+/// * `b` - A slice of elements of type `T`.
+/// * `f` - A closure computing the cost between a pair of elements at the same position.
+///
+/// # Returns
+///
+/// `Some` containing the summed cost over all positions, or `None` if `a` and `b` have different lengths.
+fn distance_by<T, F>(a: &[T], b: &[T], f: F) -> Option<u64>
+where
+    F: Fn(&T, &T) -> u64,
+{
+    if a.len() != b.len() {
+        return None;
+    }
+
+    Some(a.iter().zip(b.iter()).map(|(x, y)| f(x, y)).sum())
 }
\ No newline at end of file